@@ -0,0 +1,53 @@
+/// Commands sent from the UI's `update` loop to the running `AudioPlayer`
+/// task over an `mpsc` channel, replacing synchronous calls onto `AudioPlayer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioControlMessage {
+    Play,
+    Pause,
+    Stop,
+    Seek(usize),
+    SetSolo(Option<usize>),
+    /// Start/end ticks of an A/B practice loop, or `None` to play through.
+    /// Checked against the current tick alongside `Seek`, so the synthesis
+    /// thread can jump back to the start tick on its own once the end tick
+    /// is reached, without a round trip through the UI.
+    SetLoop(Option<(usize, usize)>),
+    /// Synthesis tempo as a percentage of the song's authored tempo (25-200),
+    /// independent of any active loop so a section can be drilled slower.
+    SetTempoScale(u8),
+    /// Master output volume, 0-100.
+    SetMasterVolume(u8),
+    /// Per-track volume, 0-100.
+    SetTrackVolume(usize, u8),
+    /// Per-track mute toggle.
+    SetTrackMuted(usize, bool),
+}
+
+/// Events emitted by the running `AudioPlayer` task back to the UI over a
+/// second `mpsc` channel, so the synthesis thread and the `iced` update loop
+/// are decoupled and the UI never blocks on the player.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioStatusMessage {
+    Playing,
+    Paused,
+    Stopped,
+    BeatChanged(usize),
+    Finished,
+    Error(String),
+}
+
+/// The player's externally visible playback state, analogous to melody's
+/// `MusicPlayerStatus`. The UI renders its play/pause icon from this rather
+/// than polling an `is_playing()` getter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerStatus {
+    Stopped(Option<usize>),
+    NowPlaying,
+    Paused,
+}
+
+impl Default for PlayerStatus {
+    fn default() -> Self {
+        PlayerStatus::Stopped(None)
+    }
+}
@@ -1,11 +1,15 @@
-use iced::widget::{column, horizontal_space, pick_list, row, text};
+use iced::widget::{column, horizontal_space, pick_list, row, slider, text};
 use iced::{keyboard, stream, Alignment, Element, Subscription, Task, Theme};
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Display;
 
+use crate::audio::control::{AudioControlMessage, AudioStatusMessage, PlayerStatus};
 use crate::audio::midi_player::AudioPlayer;
-use crate::parser::song_parser::{parse_gp_data, GpVersion, Song};
+use crate::parser::song_parser::{parse_gp_data, GpVersion, ParseError, Song};
 use crate::ui::icons::{open_icon, pause_icon, play_icon, solo_icon, stop_icon};
+use crate::ui::media_control::{media_control_subscription, MediaControlHandle, NowPlayingInfo};
+use crate::ui::notification::{notifications_view, Notification, Response, NOTIFICATION_TIMEOUT};
 use crate::ui::picker::{open_file, PickerError};
 use crate::ui::tablature::Tablature;
 use crate::ui::utils::{action_gated, action_toggle, untitled_text_table_box};
@@ -16,21 +20,36 @@ use iced::widget::scrollable::{scroll_to, AbsoluteOffset, Id};
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
-use tokio::sync::watch::{Receiver, Sender};
+use tokio::sync::mpsc;
 use tokio::sync::Mutex;
 
 const ICONS_FONT: &[u8] = include_bytes!("../../resources/icons.ttf");
 
 pub struct RuxApplication {
-    song_info: Option<SongDisplayInfo>,         // parsed song
-    track_selection: TrackSelection,            // selected track
-    all_tracks: Vec<TrackSelection>,            // all possible tracks
-    tablature: Option<Tablature>,               // loaded tablature
-    audio_player: Option<AudioPlayer>,          // audio player
-    tab_file_is_loading: bool,                  // file loading flag in progress
-    sound_font_file: Option<PathBuf>,           // sound font file
-    beat_sender: Arc<Sender<usize>>,            // beat notifier
-    beat_receiver: Arc<Mutex<Receiver<usize>>>, // beat receiver
+    song_info: Option<SongDisplayInfo>, // parsed song
+    track_selection: TrackSelection,    // selected track
+    all_tracks: Vec<TrackSelection>,    // all possible tracks
+    tablature: Option<Tablature>,       // loaded tablature
+    audio_player: Option<AudioPlayer>,  // audio player
+    tab_file_is_loading: bool,          // file loading flag in progress
+    sound_font_file: Option<PathBuf>,   // sound font file
+    control_sender: Option<mpsc::Sender<AudioControlMessage>>, // commands to the player task
+    status_receiver: Option<Arc<Mutex<mpsc::Receiver<AudioStatusMessage>>>>, // events from it
+    player_status: PlayerStatus,        // observed playback state, drives the play/pause icon
+    player_session: u64,                // bumped on every song load, to key the status subscription
+    media_control: Option<Arc<Mutex<MediaControlHandle>>>, // OS media-key/MPRIS bridge
+    master_volume: Volume,              // master output volume
+    track_mix: HashMap<usize, TrackMix>, // per-track volume/mute, keyed by track index
+    solo_track: Option<usize>,          // currently solo'd track, if any
+    playlist: Playlist,                 // opened songs & play history
+    current_tick: usize,                // playback position, for the progress bar
+    total_ticks: usize,                 // song length, for the progress bar
+    current_measure: usize,             // last measure focused, for marking A/B loop points
+    loop_start_measure: Option<usize>,  // A/B loop start, in tablature measures
+    loop_end_measure: Option<usize>,    // A/B loop end, in tablature measures
+    tempo_scale: TempoScale,            // synthesis tempo, as a % of the authored tempo
+    notifications: Vec<Notification>,   // toasts/banners shown above the tablature
+    next_notification_id: u64,          // monotonically increasing id for `notifications`
 }
 
 #[derive(Debug)]
@@ -70,21 +89,230 @@ impl Display for TrackSelection {
     }
 }
 
+/// A mixer gain level, clamped to 0-100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Volume(u8);
+
+impl Volume {
+    pub const MAX: u8 = 100;
+
+    pub fn new(value: u8) -> Self {
+        Self(value.min(Self::MAX))
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self::new(Self::MAX)
+    }
+}
+
+/// Synthesis tempo as a percentage of the song's authored tempo, clamped to
+/// 25-200 and independent of any active A/B loop, so a looped passage can be
+/// drilled slower without re-parsing the song at a different tempo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TempoScale(u8);
+
+impl TempoScale {
+    pub const MIN: u8 = 25;
+    pub const MAX: u8 = 200;
+
+    pub fn new(value: u8) -> Self {
+        Self(value.clamp(Self::MIN, Self::MAX))
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for TempoScale {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+/// Per-track mixer state, keyed by track index and kept alive across track
+/// switches so the balance a user dials in survives `TrackSelected`.
+#[derive(Debug, Clone, Copy, Default)]
+struct TrackMix {
+    volume: Volume,
+    muted: bool,
+}
+
+/// Tracks every `.gp*` file opened this session and the user's position
+/// within it: `history` is the sequence of files opened, in the order they
+/// were opened, and `history_index` counts backwards from the end of
+/// `history` (1 = the most recently opened file, 0 = nothing opened yet).
+/// "Previous" walks backward through `history`; "Next" only walks forward
+/// again through songs the user has already stepped back past — opening a
+/// file always makes it the current song immediately, so there's no separate
+/// unplayed queue to drain.
+#[derive(Debug, Default)]
+struct Playlist {
+    history: Vec<PathBuf>,
+    history_index: usize,
+}
+
+impl Playlist {
+    /// Enqueues a freshly opened file and makes it the current entry. Any
+    /// already-played "forward" history beyond the current position is
+    /// dropped first, so after a file is opened following Previous, Next has
+    /// nothing to step into rather than replaying those old entries.
+    fn open(&mut self, path: PathBuf) {
+        if self.history_index > 0 {
+            self.history.truncate(self.history.len() - self.history_index + 1);
+        }
+        self.history.push(path);
+        self.history_index = 1;
+    }
+
+    fn current(&self) -> Option<&PathBuf> {
+        (self.history_index > 0).then(|| &self.history[self.history.len() - self.history_index])
+    }
+
+    /// Steps forward through previously-played history, if the user has
+    /// receded into it; otherwise there's nothing to advance into (opening
+    /// a file makes it the current entry, not a queued one).
+    fn advance(&mut self) -> Option<PathBuf> {
+        if self.history_index > 1 {
+            self.history_index -= 1;
+            self.current().cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Steps back to the previously played song, if there is one.
+    fn recede(&mut self) -> Option<PathBuf> {
+        if self.history_index < self.history.len() {
+            self.history_index += 1;
+            self.current().cloned()
+        } else {
+            None
+        }
+    }
+
+    fn has_next(&self) -> bool {
+        self.history_index > 1
+    }
+
+    fn has_previous(&self) -> bool {
+        self.history_index < self.history.len()
+    }
+}
+
+#[cfg(test)]
+mod playlist_tests {
+    use super::Playlist;
+    use std::path::PathBuf;
+
+    fn path(name: &str) -> PathBuf {
+        PathBuf::from(name)
+    }
+
+    #[test]
+    fn open_then_prev_then_next() {
+        let mut playlist = Playlist::default();
+        playlist.open(path("a.gp5"));
+        playlist.open(path("b.gp5"));
+        assert_eq!(playlist.current(), Some(&path("b.gp5")));
+
+        assert_eq!(playlist.recede(), Some(path("a.gp5")));
+        assert!(!playlist.has_previous());
+        assert!(playlist.has_next());
+
+        assert_eq!(playlist.advance(), Some(path("b.gp5")));
+        assert_eq!(playlist.current(), Some(&path("b.gp5")));
+        assert!(!playlist.has_next());
+    }
+
+    #[test]
+    fn open_after_recede_discards_forward_history() {
+        let mut playlist = Playlist::default();
+        playlist.open(path("a.gp5"));
+        playlist.open(path("b.gp5"));
+        playlist.recede(); // back to a.gp5, b.gp5 is now "forward" history
+
+        playlist.open(path("c.gp5"));
+        assert_eq!(playlist.current(), Some(&path("c.gp5")));
+        assert!(!playlist.has_next());
+
+        // advancing should resume into freshly opened songs, not replay the
+        // discarded b.gp5
+        playlist.open(path("d.gp5"));
+        assert_eq!(playlist.current(), Some(&path("d.gp5")));
+        assert_eq!(playlist.recede(), Some(path("c.gp5")));
+        assert_eq!(playlist.advance(), Some(path("d.gp5")));
+    }
+
+    #[test]
+    fn advance_with_empty_playlist_does_nothing() {
+        let mut playlist = Playlist::default();
+        assert_eq!(playlist.advance(), None);
+        assert_eq!(playlist.recede(), None);
+        assert!(!playlist.has_next());
+        assert!(!playlist.has_previous());
+    }
+}
+
+/// Reads a `.gp*` file already tracked by the playlist from disk, reusing the
+/// same `(contents, file_name)` shape the file picker produces so both feed
+/// the same parse -> `Tablature::new` -> `AudioPlayer::new` pipeline.
+async fn read_playlist_entry(path: PathBuf) -> Result<(Vec<u8>, String), String> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    tokio::fs::read(&path)
+        .await
+        .map(|contents| (contents, file_name))
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+}
+
+/// Formats a `Duration` as `m:ss` for the elapsed/total timestamp readout.
+fn format_duration(duration: std::time::Duration) -> String {
+    let total_secs = duration.as_secs();
+    format!("{}:{:02}", total_secs / 60, total_secs % 60)
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     OpenFile,                                           // open file dialog
-    FileOpened(Result<(Vec<u8>, String), PickerError>), // file content & file name
+    FileOpened(Result<(PathBuf, Vec<u8>, String), PickerError>), // real path, content & display name
+    SongLoaded(Result<(Vec<u8>, String), String>),      // playlist navigation load result
     TrackSelected(TrackSelection),                      // track selection
-    FocusMeasure(usize), // used when clicking on measure in tablature
-    FocusTick(usize),    // focus on a specific tick in the tablature
-    PlayPause,           // toggle play/pause
-    StopPlayer,          // stop playback
-    ToggleSolo,          // toggle solo mode
+    FocusMeasure(usize),             // used when clicking on measure in tablature
+    SeekToTick(usize),               // seek playback to a specific tick, e.g. from the progress bar
+    PlayPause,                       // toggle play/pause
+    StopPlayer,                      // stop playback
+    ToggleSolo,                      // toggle solo mode
+    SetMasterVolume(u8),             // master output volume, 0-100
+    SetTrackVolume(usize, u8),       // per-track volume, 0-100
+    ToggleMute(usize),               // per-track mute
+    NextSong,                        // advance to the next song in the playlist
+    PrevSong,                        // step back to the previously played song
+    AudioStatus(AudioStatusMessage), // event from the running AudioPlayer task
+    DismissNotification(u64),        // dismiss a toast/banner, by id
+    SetLoopStart(usize),              // mark the A/B loop start at a measure
+    SetLoopEnd(usize),                // mark the A/B loop end at a measure
+    ClearLoop,                        // clear the A/B loop
+    SetTempoScale(u8),                // synthesis tempo, as a % of the authored tempo
 }
 
 impl RuxApplication {
     fn new(sound_font_file: Option<PathBuf>) -> Self {
-        let (beat_sender, beat_receiver) = tokio::sync::watch::channel(0);
+        let media_control = match MediaControlHandle::new() {
+            Ok(handle) => Some(Arc::new(Mutex::new(handle))),
+            Err(err) => {
+                log::warn!("Failed to initialize OS media controls: {:?}", err);
+                None
+            }
+        };
         Self {
             song_info: None,
             track_selection: TrackSelection::default(),
@@ -93,11 +321,206 @@ impl RuxApplication {
             audio_player: None,
             tab_file_is_loading: false,
             sound_font_file,
-            beat_receiver: Arc::new(Mutex::new(beat_receiver)),
-            beat_sender: Arc::new(beat_sender),
+            control_sender: None,
+            status_receiver: None,
+            player_status: PlayerStatus::default(),
+            player_session: 0,
+            media_control,
+            master_volume: Volume::default(),
+            track_mix: HashMap::new(),
+            solo_track: None,
+            playlist: Playlist::default(),
+            current_tick: 0,
+            total_ticks: 0,
+            current_measure: 0,
+            loop_start_measure: None,
+            loop_end_measure: None,
+            tempo_scale: TempoScale::default(),
+            notifications: Vec::new(),
+            next_notification_id: 0,
+        }
+    }
+
+    /// Turns a `Response` into a visible `Notification` and, for non-fatal
+    /// ones, a `Task` that auto-dismisses it after `NOTIFICATION_TIMEOUT`.
+    /// `Response::Success` is a no-op, since it has nothing to show.
+    fn notify(&mut self, response: Response) -> Task<Message> {
+        let id = self.next_notification_id;
+        let Some(notification) = Notification::from_response(id, response) else {
+            return Task::none();
+        };
+        let auto_dismisses = notification.auto_dismisses();
+        self.next_notification_id += 1;
+        self.notifications.push(notification);
+        if auto_dismisses {
+            Task::perform(tokio::time::sleep(NOTIFICATION_TIMEOUT), move |_| {
+                Message::DismissNotification(id)
+            })
+        } else {
+            Task::none()
+        }
+    }
+
+    fn track_mix(&self, track: usize) -> TrackMix {
+        self.track_mix.get(&track).copied().unwrap_or_default()
+    }
+
+    /// Pushes the currently loaded song's metadata out to the OS media-control panel.
+    fn publish_now_playing(&self) {
+        let (Some(media_control), Some(song_info)) = (&self.media_control, &self.song_info) else {
+            return;
+        };
+        let info = NowPlayingInfo {
+            title: song_info.name.clone(),
+            artist: song_info.artist.clone(),
+            track: self.track_selection.name.clone(),
+        };
+        let media_control = media_control.clone();
+        tokio::spawn(async move {
+            media_control.lock().await.publish_metadata(&info);
+        });
+    }
+
+    /// Pushes the current play/pause/stop state out to the OS media-control panel.
+    fn publish_playback_state(&self) {
+        let Some(media_control) = &self.media_control else {
+            return;
+        };
+        let is_playing = matches!(self.player_status, PlayerStatus::NowPlaying);
+        let stopped = matches!(self.player_status, PlayerStatus::Stopped(_));
+        let media_control = media_control.clone();
+        tokio::spawn(async move {
+            let mut media_control = media_control.lock().await;
+            if stopped {
+                media_control.publish_stopped();
+            } else {
+                media_control.publish_playback(is_playing);
+            }
+        });
+    }
+
+    /// Sends a command to the running `AudioPlayer` task without blocking the
+    /// update loop; silently dropped if no song is loaded or the task's
+    /// channel is full.
+    fn send_control(&self, command: AudioControlMessage) {
+        if let Some(control_sender) = &self.control_sender {
+            let _ = control_sender.try_send(command);
+        }
+    }
+
+    /// Records the current playback tick and the measure it falls in, so
+    /// `current_measure` (used to mark A/B loop points) tracks the playhead
+    /// during playback, not just manual tablature clicks.
+    fn track_tick(&mut self, tick: usize) {
+        self.current_tick = tick;
+        if let Some(audio_player) = &self.audio_player {
+            self.current_measure = audio_player.measure_for_tick(tick);
         }
     }
 
+    /// Resolves `loop_start_measure`/`loop_end_measure` to ticks through the
+    /// same measure/tick mapping `AudioPlayer` uses for `focus_measure`, and
+    /// pushes the result out to the player task. A single measure (start ==
+    /// end) loops just that measure; an out-of-order or not-yet-complete
+    /// pair clears the loop instead.
+    fn sync_loop(&self) {
+        let Some(audio_player) = &self.audio_player else {
+            return;
+        };
+        let range = match (self.loop_start_measure, self.loop_end_measure) {
+            (Some(start), Some(end)) if start <= end => Some((
+                audio_player.tick_for_measure(start),
+                audio_player.tick_for_measure(end),
+            )),
+            _ => None,
+        };
+        self.send_control(AudioControlMessage::SetLoop(range));
+    }
+
+    /// Parses a newly loaded `.gp*` file's bytes and swaps it in as the
+    /// current song, shared by both the file-picker and playlist navigation.
+    fn load_song(&mut self, contents: &[u8], file_name: String) -> Task<Message> {
+        let song = match parse_gp_data(contents) {
+            Ok(song) => song,
+            Err(ParseError::UnsupportedVersion(version)) => {
+                log::warn!("Unsupported Guitar Pro version: {:?}", version);
+                return self.notify(Response::Fatal(format!(
+                    "Unsupported Guitar Pro version: {:?}",
+                    version
+                )));
+            }
+            Err(err) => {
+                log::warn!("Failed to parse GP file: {}", err);
+                return self.notify(Response::Failure(format!(
+                    "Failed to parse Guitar Pro file: {}",
+                    err
+                )));
+            }
+        };
+        // build all tracks selection
+        let track_selections: Vec<_> = song
+            .tracks
+            .iter()
+            .enumerate()
+            .map(|(index, track)| TrackSelection::new(index, track.name.clone()))
+            .collect();
+        self.all_tracks.clone_from(&track_selections);
+        self.song_info = Some(SongDisplayInfo::new(&song, file_name));
+        // select first track by default
+        let default_track = 0;
+        let default_track_selection = track_selections[default_track].clone();
+        self.track_selection = default_track_selection;
+        // share song ownership with tablature and player
+        let song_rc = Rc::new(song);
+        let tablature_scroll_id = Id::new(Cow::Borrowed("tablature-scroll-elements"));
+        let tablature =
+            Tablature::new(song_rc.clone(), default_track, tablature_scroll_id.clone());
+        self.tablature = Some(tablature);
+        // stop previous audio player if any
+        self.send_control(AudioControlMessage::Stop);
+        // set up a fresh command/status peer for the new player task, replacing
+        // the previous pair so the stale subscription is dropped
+        let (control_sender, control_receiver) = mpsc::channel(32);
+        let (status_sender, status_receiver) = mpsc::channel(32);
+        self.player_session += 1;
+        // audio player initialization
+        let audio_player = AudioPlayer::new(
+            song_rc.clone(),
+            song_rc.tempo.value,
+            self.sound_font_file.clone(),
+            control_receiver,
+            status_sender,
+        );
+        self.audio_player = Some(audio_player);
+        self.control_sender = Some(control_sender);
+        self.status_receiver = Some(Arc::new(Mutex::new(status_receiver)));
+        self.player_status = PlayerStatus::Stopped(None);
+        self.solo_track = None;
+        // per-track volume/mute is keyed by index into the *previous*
+        // song's tracks; reset it rather than silently desyncing the UI
+        // from a freshly loaded player that doesn't know about it
+        self.track_mix = HashMap::new();
+        self.current_tick = 0;
+        self.total_ticks = 0;
+        self.current_measure = 0;
+        self.loop_start_measure = None;
+        self.loop_end_measure = None;
+        self.send_control(AudioControlMessage::SetMasterVolume(
+            self.master_volume.value(),
+        ));
+        self.send_control(AudioControlMessage::SetTempoScale(self.tempo_scale.value()));
+        // total_ticks only reads immutable per-song data computed at parse
+        // time, so it's safe to call directly on the shared handle without
+        // racing the synthesis thread the way a setter would.
+        if let Some(audio_player) = &self.audio_player {
+            self.total_ticks = audio_player.total_ticks();
+        }
+        // publish new song metadata to the OS media-control panel
+        self.publish_now_playing();
+        // reset tablature scroll
+        scroll_to(tablature_scroll_id, AbsoluteOffset::default())
+    }
+
     pub fn start(args: ApplicationArgs) -> iced::Result {
         iced::application(
             RuxApplication::title,
@@ -133,6 +556,8 @@ impl RuxApplication {
                     tablature.update_track(selection.index);
                 }
                 self.track_selection = selection;
+                // keep the OS media-control panel's track field in sync
+                self.publish_now_playing();
                 Task::none()
             }
             Message::OpenFile => {
@@ -146,61 +571,18 @@ impl RuxApplication {
             Message::FileOpened(result) => {
                 self.tab_file_is_loading = false;
                 match result {
-                    Ok((contents, file_name)) => {
-                        if let Ok(song) = parse_gp_data(&contents) {
-                            // build all tracks selection
-                            let track_selections: Vec<_> = song
-                                .tracks
-                                .iter()
-                                .enumerate()
-                                .map(|(index, track)| {
-                                    TrackSelection::new(index, track.name.clone())
-                                })
-                                .collect();
-                            self.all_tracks.clone_from(&track_selections);
-                            self.song_info = Some(SongDisplayInfo::new(&song, file_name));
-                            // select first track by default
-                            let default_track = 0;
-                            let default_track_selection = track_selections[default_track].clone();
-                            self.track_selection = default_track_selection;
-                            // share song ownership with tablature and player
-                            let song_rc = Rc::new(song);
-                            let tablature_scroll_id =
-                                Id::new(Cow::Borrowed("tablature-scroll-elements"));
-                            let tablature = Tablature::new(
-                                song_rc.clone(),
-                                default_track,
-                                tablature_scroll_id.clone(),
-                            );
-                            self.tablature = Some(tablature);
-                            // stop previous audio player if any
-                            if let Some(audio_player) = &mut self.audio_player {
-                                audio_player.stop();
-                            }
-                            // audio player initialization
-                            let audio_player = AudioPlayer::new(
-                                song_rc.clone(),
-                                song_rc.tempo.value,
-                                self.sound_font_file.clone(),
-                                self.beat_sender.clone(),
-                            );
-                            self.audio_player = Some(audio_player);
-                            // reset tablature scroll
-                            scroll_to(tablature_scroll_id, AbsoluteOffset::default())
-                        } else {
-                            log::warn!("Failed to parse GP file");
-                            // TODO show alert popup
-                            Task::none()
-                        }
+                    Ok((path, contents, file_name)) => {
+                        self.playlist.open(path);
+                        self.load_song(&contents, file_name)
                     }
                     Err(err) => {
                         log::warn!("Failed to read GP file: {}", err);
-                        // TODO show alert popup
-                        Task::none()
+                        self.notify(Response::Failure(format!("Failed to read file: {}", err)))
                     }
                 }
             }
             Message::FocusMeasure(measure_id) => {
+                self.current_measure = measure_id;
                 // focus measure in tablature
                 if let Some(tablature) = &mut self.tablature {
                     tablature.focus_on_measure(measure_id);
@@ -211,24 +593,26 @@ impl RuxApplication {
                 }
                 Task::none()
             }
-            Message::FocusTick(tick) => {
+            Message::SeekToTick(tick) => {
+                self.track_tick(tick);
+                self.send_control(AudioControlMessage::Seek(tick));
                 if let Some(tablature) = &mut self.tablature {
                     tablature.focus_on_tick(tick);
                 }
                 Task::none()
             }
             Message::PlayPause => {
-                if let Some(audio_player) = &mut self.audio_player {
-                    audio_player.toggle_play();
-                }
+                let command = if matches!(self.player_status, PlayerStatus::NowPlaying) {
+                    AudioControlMessage::Pause
+                } else {
+                    AudioControlMessage::Play
+                };
+                self.send_control(command);
                 Task::none()
             }
             Message::StopPlayer => {
-                if let (Some(audio_player), Some(tablature)) =
-                    (&mut self.audio_player, &mut self.tablature)
-                {
-                    // stop audio player
-                    audio_player.stop();
+                self.send_control(AudioControlMessage::Stop);
+                if let Some(tablature) = &mut self.tablature {
                     // reset tablature focus
                     tablature.focus_on_measure(0);
                     // reset tablature scroll
@@ -238,10 +622,111 @@ impl RuxApplication {
                 }
             }
             Message::ToggleSolo => {
-                if let Some(audio_player) = &mut self.audio_player {
-                    let track = self.track_selection.index;
-                    audio_player.toggle_solo_mode(track);
+                let track = self.track_selection.index;
+                self.solo_track = if self.solo_track == Some(track) {
+                    None
+                } else {
+                    Some(track)
+                };
+                self.send_control(AudioControlMessage::SetSolo(self.solo_track));
+                Task::none()
+            }
+            Message::SetMasterVolume(value) => {
+                self.master_volume = Volume::new(value);
+                self.send_control(AudioControlMessage::SetMasterVolume(
+                    self.master_volume.value(),
+                ));
+                Task::none()
+            }
+            Message::SetTrackVolume(track, value) => {
+                let mix = self.track_mix.entry(track).or_default();
+                mix.volume = Volume::new(value);
+                self.send_control(AudioControlMessage::SetTrackVolume(track, mix.volume.value()));
+                Task::none()
+            }
+            Message::ToggleMute(track) => {
+                let mix = self.track_mix.entry(track).or_default();
+                mix.muted = !mix.muted;
+                self.send_control(AudioControlMessage::SetTrackMuted(track, mix.muted));
+                Task::none()
+            }
+            Message::SongLoaded(result) => {
+                match result {
+                    Ok((contents, file_name)) => self.load_song(&contents, file_name),
+                    Err(err) => {
+                        log::warn!("Failed to load playlist entry: {}", err);
+                        self.notify(Response::Failure(err))
+                    }
+                }
+            }
+            Message::NextSong => match self.playlist.advance() {
+                Some(path) => Task::perform(read_playlist_entry(path), Message::SongLoaded),
+                None => Task::none(),
+            },
+            Message::PrevSong => match self.playlist.recede() {
+                Some(path) => Task::perform(read_playlist_entry(path), Message::SongLoaded),
+                None => Task::none(),
+            },
+            Message::AudioStatus(status) => match status {
+                AudioStatusMessage::Playing => {
+                    self.player_status = PlayerStatus::NowPlaying;
+                    self.publish_playback_state();
+                    Task::none()
+                }
+                AudioStatusMessage::Paused => {
+                    self.player_status = PlayerStatus::Paused;
+                    self.publish_playback_state();
+                    Task::none()
+                }
+                AudioStatusMessage::Stopped => {
+                    self.player_status = PlayerStatus::Stopped(Some(self.current_tick));
+                    self.publish_playback_state();
+                    Task::none()
+                }
+                AudioStatusMessage::BeatChanged(tick) => {
+                    self.track_tick(tick);
+                    if let Some(tablature) = &mut self.tablature {
+                        tablature.focus_on_tick(tick);
+                    }
+                    Task::none()
+                }
+                AudioStatusMessage::Finished => {
+                    self.player_status = PlayerStatus::Stopped(None);
+                    self.publish_playback_state();
+                    // auto-advance to the next song in the forward history, if any
+                    match self.playlist.advance() {
+                        Some(path) => Task::perform(read_playlist_entry(path), Message::SongLoaded),
+                        None => Task::none(),
+                    }
+                }
+                AudioStatusMessage::Error(message) => {
+                    log::warn!("Audio player error: {}", message);
+                    self.notify(Response::Fatal(message))
                 }
+            },
+            Message::DismissNotification(id) => {
+                self.notifications.retain(|n| n.id != id);
+                Task::none()
+            }
+            Message::SetLoopStart(measure) => {
+                self.loop_start_measure = Some(measure);
+                self.sync_loop();
+                Task::none()
+            }
+            Message::SetLoopEnd(measure) => {
+                self.loop_end_measure = Some(measure);
+                self.sync_loop();
+                Task::none()
+            }
+            Message::ClearLoop => {
+                self.loop_start_measure = None;
+                self.loop_end_measure = None;
+                self.send_control(AudioControlMessage::SetLoop(None));
+                Task::none()
+            }
+            Message::SetTempoScale(value) => {
+                self.tempo_scale = TempoScale::new(value);
+                self.send_control(AudioControlMessage::SetTempoScale(self.tempo_scale.value()));
                 Task::none()
             }
         }
@@ -254,15 +739,25 @@ impl RuxApplication {
             (!self.tab_file_is_loading).then_some(Message::OpenFile),
         );
 
-        let player_control = if let Some(audio_player) = &self.audio_player {
-            let (icon, message) = if audio_player.is_playing() {
+        let player_control = if self.audio_player.is_some() {
+            let (icon, message) = if matches!(self.player_status, PlayerStatus::NowPlaying) {
                 (pause_icon(), "Pause")
             } else {
                 (play_icon(), "Play")
             };
             let play_button = action_gated(icon, message, Some(Message::PlayPause));
             let stop_button = action_gated(stop_icon(), "Stop", Some(Message::StopPlayer));
-            row![play_button, stop_button,]
+            let prev_button = action_gated(
+                text("|<"),
+                "Previous song",
+                self.playlist.has_previous().then_some(Message::PrevSong),
+            );
+            let next_button = action_gated(
+                text(">|"),
+                "Next song",
+                self.playlist.has_next().then_some(Message::NextSong),
+            );
+            row![prev_button, play_button, stop_button, next_button,]
                 .spacing(10)
                 .align_y(Alignment::Center)
         } else {
@@ -276,9 +771,7 @@ impl RuxApplication {
                 solo_icon(),
                 "Solo",
                 Message::ToggleSolo,
-                self.audio_player
-                    .as_ref()
-                    .is_some_and(|p| p.solo_track_id().is_some()),
+                self.solo_track == Some(self.track_selection.index),
             );
 
             let track_pick_list = pick_list(
@@ -289,21 +782,101 @@ impl RuxApplication {
             .text_size(14)
             .padding([5, 10]);
 
-            row![solo_mode, track_pick_list,]
+            let track = self.track_selection.index;
+            let mix = self.track_mix(track);
+            let mute_button =
+                action_toggle(text("M"), "Mute", Message::ToggleMute(track), mix.muted);
+            let track_volume = slider(0..=Volume::MAX, mix.volume.value(), move |value| {
+                Message::SetTrackVolume(track, value)
+            })
+            .width(100);
+
+            row![solo_mode, track_pick_list, mute_button, track_volume,]
+                .spacing(10)
+                .align_y(Alignment::Center)
+        };
+
+        let master_volume = row![
+            text("Volume"),
+            slider(0..=Volume::MAX, self.master_volume.value(), |value| {
+                Message::SetMasterVolume(value)
+            })
+            .width(100),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let loop_control = if self.audio_player.is_some() {
+            let mark_start = action_gated(
+                text("A"),
+                "Mark loop start at current measure",
+                Some(Message::SetLoopStart(self.current_measure)),
+            );
+            let mark_end = action_gated(
+                text("B"),
+                "Mark loop end at current measure",
+                Some(Message::SetLoopEnd(self.current_measure)),
+            );
+            let clear = action_gated(
+                text("x"),
+                "Clear loop",
+                (self.loop_start_measure.is_some() || self.loop_end_measure.is_some())
+                    .then_some(Message::ClearLoop),
+            );
+            row![mark_start, mark_end, clear]
                 .spacing(10)
                 .align_y(Alignment::Center)
+        } else {
+            row![horizontal_space()]
         };
 
+        let tempo_control = row![
+            text("Tempo"),
+            slider(
+                TempoScale::MIN..=TempoScale::MAX,
+                self.tempo_scale.value(),
+                |value| { Message::SetTempoScale(value) }
+            )
+            .width(100),
+            text(format!("{}%", self.tempo_scale.value())).size(14),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
         let controls = row![
             open_file,
             horizontal_space(),
             player_control,
             horizontal_space(),
             track_control,
+            horizontal_space(),
+            loop_control,
+            horizontal_space(),
+            master_volume,
+            horizontal_space(),
+            tempo_control,
         ]
         .spacing(10)
         .align_y(Alignment::Center);
 
+        let progress = if let Some(audio_player) = &self.audio_player {
+            let elapsed = format_duration(audio_player.duration_at_tick(self.current_tick));
+            let total = format_duration(audio_player.total_duration());
+            row![
+                text(format!("{} / {}", elapsed, total)).size(14),
+                slider(
+                    0..=self.total_ticks as u32,
+                    self.current_tick as u32,
+                    |tick| { Message::SeekToTick(tick as usize) }
+                )
+                .width(iced::Length::Fill),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+        } else {
+            row![horizontal_space()]
+        };
+
         let status = row![
             text(if let Some(song) = &self.song_info {
                 format!("{} by {}", song.name, song.artist)
@@ -311,6 +884,8 @@ impl RuxApplication {
                 String::new()
             }),
             horizontal_space(),
+            progress,
+            horizontal_space(),
             text(if let Some(song) = &self.song_info {
                 format!("{:?}", song.gp_version)
             } else {
@@ -324,36 +899,36 @@ impl RuxApplication {
             .as_ref()
             .map_or(untitled_text_table_box().into(), |t| t.view());
 
-        column![controls, tablature_view, status,]
-            .spacing(20)
-            .padding(10)
-            .into()
+        let mut content = column![controls].spacing(20).padding(10);
+        if !self.notifications.is_empty() {
+            content = content.push(notifications_view(&self.notifications));
+        }
+        content.push(tablature_view).push(status).into()
     }
 
     fn theme(&self) -> Theme {
         Theme::Dark
     }
 
-    fn audio_player_beat_subscription(&self) -> impl Stream<Item = Message> {
-        let beat_receiver = self.beat_receiver.clone();
+    /// Relays `AudioStatusMessage`s from the running player task to `update`,
+    /// keyed to the current song so a stale task's events can't leak into a
+    /// freshly loaded one.
+    fn audio_status_subscription(
+        status_receiver: Arc<Mutex<mpsc::Receiver<AudioStatusMessage>>>,
+    ) -> impl Stream<Item = Message> {
         stream::channel(1, move |mut output| async move {
-            let mut receiver = beat_receiver.lock().await;
-            loop {
-                // get tick from audio player
-                let tick = *receiver.borrow_and_update();
-                // publish to UI
+            let mut receiver = status_receiver.lock().await;
+            while let Some(status) = receiver.recv().await {
                 output
-                    .send(Message::FocusTick(tick))
+                    .send(Message::AudioStatus(status))
                     .await
                     .expect("send failed");
-                // wait for next beat
-                receiver.changed().await.expect("receiver failed");
             }
         })
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        let mut subscriptions = Vec::with_capacity(2);
+        let mut subscriptions = Vec::with_capacity(3);
 
         // keyboard event subscription
         let keyboard_subscription = keyboard::on_key_press(|key, _modifiers| match key.as_ref() {
@@ -362,12 +937,21 @@ impl RuxApplication {
         });
         subscriptions.push(keyboard_subscription);
 
-        // next beat notifier subscription
-        let audio_player_beat_subscription = self.audio_player_beat_subscription();
-        subscriptions.push(Subscription::run_with_id(
-            "audio-player-beat",
-            audio_player_beat_subscription,
-        ));
+        // audio player status subscription
+        if let Some(status_receiver) = &self.status_receiver {
+            subscriptions.push(Subscription::run_with_id(
+                ("audio-status", self.player_session),
+                Self::audio_status_subscription(status_receiver.clone()),
+            ));
+        }
+
+        // OS media-key / MPRIS-SMTC transport subscription
+        if let Some(media_control) = &self.media_control {
+            subscriptions.push(Subscription::run_with_id(
+                "media-control",
+                media_control_subscription(media_control.clone()),
+            ));
+        }
 
         Subscription::batch(subscriptions)
     }
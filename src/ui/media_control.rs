@@ -0,0 +1,106 @@
+use iced::futures::{SinkExt, Stream};
+use iced::stream;
+use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::ui::application::Message;
+
+/// Bridges OS-level media keys and the Linux MPRIS / Windows SMTC panels into
+/// the `iced` update loop, mirroring the `SystemControlWrapper`/`ControlAction`
+/// bridge in the muss player: incoming `MediaControlEvent`s are translated into
+/// `Message`s, and outgoing playback state/metadata is pushed back out to the OS.
+pub struct MediaControlHandle {
+    controls: MediaControls,
+    events: Receiver<MediaControlEvent>,
+}
+
+/// Metadata published to the OS media-control panel for the currently loaded song.
+#[derive(Debug, Clone, Default)]
+pub struct NowPlayingInfo {
+    pub title: String,
+    pub artist: String,
+    pub track: String,
+}
+
+impl MediaControlHandle {
+    pub fn new() -> Result<Self, souvlaki::Error> {
+        let config = PlatformConfig {
+            dbus_name: "ruxguitar",
+            display_name: "RuxGuitar",
+            hwnd: None,
+        };
+        let mut controls = MediaControls::new(config)?;
+        let (sender, events) = channel();
+        controls.attach(move |event| {
+            let _ = sender.send(event);
+        })?;
+        Ok(Self { controls, events })
+    }
+
+    pub fn publish_metadata(&mut self, info: &NowPlayingInfo) {
+        let _ = self.controls.set_metadata(MediaMetadata {
+            title: Some(&info.title),
+            artist: Some(&info.artist),
+            album: Some(&info.track),
+            ..Default::default()
+        });
+    }
+
+    pub fn publish_playback(&mut self, is_playing: bool) {
+        let playback = if is_playing {
+            MediaPlayback::Playing { progress: None }
+        } else {
+            MediaPlayback::Paused { progress: None }
+        };
+        let _ = self.controls.set_playback(playback);
+    }
+
+    pub fn publish_stopped(&mut self) {
+        let _ = self.controls.set_playback(MediaPlayback::Stopped);
+    }
+}
+
+/// Maps a raw `MediaControlEvent` onto the subset of `Message`s the transport
+/// controls can drive. Unhandled events (seek, volume, raise/quit) are dropped.
+fn to_message(event: MediaControlEvent) -> Option<Message> {
+    match event {
+        MediaControlEvent::Play | MediaControlEvent::Pause | MediaControlEvent::Toggle => {
+            Some(Message::PlayPause)
+        }
+        MediaControlEvent::Stop => Some(Message::StopPlayer),
+        MediaControlEvent::Next => Some(Message::NextSong),
+        MediaControlEvent::Previous => Some(Message::PrevSong),
+        _ => None,
+    }
+}
+
+/// Subscription stream yielding `Message`s for hardware media keys and
+/// MPRIS/SMTC transport commands, polled alongside `audio_status_subscription`.
+pub fn media_control_subscription(
+    handle: Arc<Mutex<MediaControlHandle>>,
+) -> impl Stream<Item = Message> {
+    stream::channel(1, move |mut output| async move {
+        loop {
+            let event = {
+                let handle = handle.lock().await;
+                // `Receiver::recv` blocks the thread, which we can't do while
+                // holding this async lock, so poll the non-blocking
+                // `try_iter` instead and fall back to a short sleep between
+                // polls when it comes up empty.
+                handle.events.try_iter().next()
+            };
+            match event {
+                Some(event) => {
+                    if let Some(message) = to_message(event) {
+                        output.send(message).await.expect("send failed");
+                    }
+                }
+                None => {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                }
+            }
+        }
+    })
+}
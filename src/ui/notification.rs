@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use iced::widget::{button, container, row, text};
+use iced::{Background, Border, Color, Element};
+
+use crate::ui::application::Message;
+
+/// Outcome of an operation whose result should surface to the user rather
+/// than just being logged, modeled on the `Response` enum in the
+/// Luminescent Dreams music player client: `Success` leaves no visible
+/// trace, `Failure` is recoverable and auto-dismisses, `Fatal` stays on
+/// screen until the user dismisses it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Response {
+    Success,
+    Failure(String),
+    Fatal(String),
+}
+
+/// How long a non-fatal notification stays on screen before auto-dismissing.
+pub const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// Visual weight of a `Notification`, driving its toast styling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single toast/banner shown above the tablature, surfacing things like
+/// unsupported `GpVersion`s, missing soundfont files, and audio-device init
+/// failures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub id: u64,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Notification {
+    /// Builds a `Notification` from a `Response`, or `None` for `Success`
+    /// since those have nothing to show the user.
+    pub fn from_response(id: u64, response: Response) -> Option<Self> {
+        let (severity, message) = match response {
+            Response::Success => return None,
+            Response::Failure(message) => (Severity::Warning, message),
+            Response::Fatal(message) => (Severity::Error, message),
+        };
+        Some(Self {
+            id,
+            severity,
+            message,
+        })
+    }
+
+    /// Non-fatal notifications auto-dismiss after `NOTIFICATION_TIMEOUT`;
+    /// fatal ones wait for the user to dismiss them explicitly.
+    pub fn auto_dismisses(&self) -> bool {
+        self.severity != Severity::Error
+    }
+
+    fn accent(&self) -> Color {
+        match self.severity {
+            Severity::Warning => Color::from_rgb8(0xE0, 0xA5, 0x26),
+            Severity::Error => Color::from_rgb8(0xD9, 0x4F, 0x4F),
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let accent = self.accent();
+        let dismiss = button(text("x").size(14))
+            .padding([0, 6])
+            .style(move |_theme, _status| button::Style {
+                background: None,
+                text_color: accent,
+                ..Default::default()
+            })
+            .on_press(Message::DismissNotification(self.id));
+        container(
+            row![text(self.message.clone()).size(14), dismiss]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+        )
+        .padding(8)
+        .style(move |_theme| container::Style {
+            background: Some(Background::Color(Color {
+                a: 0.15,
+                ..accent
+            })),
+            border: Border {
+                color: accent,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+    }
+}
+
+/// Renders the current notifications as a stacked column of toasts/banners,
+/// most recent first, or an empty row when there are none to show.
+pub fn notifications_view(notifications: &[Notification]) -> Element<Message> {
+    if notifications.is_empty() {
+        return row![].into();
+    }
+    iced::widget::column(notifications.iter().rev().map(Notification::view))
+        .spacing(6)
+        .into()
+}
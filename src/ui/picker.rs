@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use rfd::AsyncFileDialog;
+
+/// Why the native file-picker dialog didn't hand back a usable `.gp*` file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PickerError {
+    /// The user closed the dialog without picking a file.
+    Cancelled,
+    /// The file was picked but couldn't be read back off disk.
+    ReadFailed(String),
+}
+
+impl Display for PickerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PickerError::Cancelled => write!(f, "no file selected"),
+            PickerError::ReadFailed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Opens the native file-picker dialog for a `.gp*` tab and reads it back,
+/// returning the real filesystem path alongside its contents and display
+/// name. The path is what `Playlist::open` stores, so Previous/Next/auto-
+/// advance can later re-read the same file from disk.
+pub async fn open_file() -> Result<(PathBuf, Vec<u8>, String), PickerError> {
+    let handle = AsyncFileDialog::new()
+        .add_filter("Guitar Pro", &["gp3", "gp4", "gp5", "gpx"])
+        .pick_file()
+        .await
+        .ok_or(PickerError::Cancelled)?;
+    let path = handle.path().to_path_buf();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let contents = handle.read().await;
+    Ok((path, contents, file_name))
+}